@@ -27,6 +27,10 @@
 //!
 //! - Using LF (`\n`) instead of CRLF (`\r\n`) as the newline is permitted.
 //! - Customizing the separator character is possible.
+//! - With the `serde` feature, rows can be deserialized directly into a struct or tuple; see
+//!   [`CsvRowIter::deserialize()`].
+//! - An escape byte can be used inside quoted cells in place of doubled quotes; see
+//!   [`Csv::with_escape()`].
 //!
 //! # Examples
 //!
@@ -59,7 +63,7 @@
 
 extern crate alloc;
 
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, string::String, vec::Vec};
 use core::{
     hash::{Hash, Hasher},
     mem::MaybeUninit,
@@ -76,6 +80,10 @@ use thiserror::Error;
 pub struct Csv<'a, const SEP: u8 = b','> {
     buf: &'a [u8],
     state: IterState,
+    terminator: RecordTerminator,
+    quote: u8,
+    escape: Option<u8>,
+    comment_prefix: Option<&'a [u8]>,
 }
 
 impl<'a> Csv<'a> {
@@ -94,6 +102,10 @@ impl<'a> Csv<'a> {
         Csv {
             buf,
             state: IterState::Cell(0),
+            terminator: RecordTerminator::CRLF,
+            quote: b'"',
+            escape: None,
+            comment_prefix: None,
         }
     }
 
@@ -111,6 +123,10 @@ impl<'a> Csv<'a> {
         Csv {
             buf,
             state: IterState::Cell(0),
+            terminator: RecordTerminator::CRLF,
+            quote: b'"',
+            escape: None,
+            comment_prefix: None,
         }
     }
 }
@@ -130,7 +146,145 @@ impl<'a, const SEP: u8> Csv<'a, SEP> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn into_rows<const COLS: usize>(self) -> CsvRowIter<'a, COLS, SEP> {
-        CsvRowIter { csv: self }
+        CsvRowIter { csv: self, record: 0 }
+    }
+
+    /// Create a wrapper iterator that yields each row as a slice of cells, for CSV with a
+    /// variable number of columns per row.
+    ///
+    /// Unlike [`Csv::into_rows()`], this doesn't require a fixed `COLS` and never errors on a
+    /// column count mismatch. See [`CsvFlexibleRowIter`] for usage.
+    pub fn into_flexible_rows(self) -> CsvFlexibleRowIter<'a, SEP> {
+        CsvFlexibleRowIter { csv: self }
+    }
+
+    /// Builds a [`CsvIndex`] recording the byte offset of every row in this parser's buffer, so
+    /// later rows can be jumped to directly instead of re-parsing from the start.
+    ///
+    /// Useful for sampling or random access into large files. This honors wherever this [`Csv`]
+    /// is currently positioned, so call it after [`Csv::skip_rows()`] or [`Csv::auto_skip()`] to
+    /// exclude preceding rows from the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::{Csv, CsvIterItem};
+    ///
+    /// let index = Csv::new(b"a,b,c\n1,2,3\n4,5,6\n").build_index();
+    /// assert_eq!(index.len(), 3);
+    ///
+    /// let CsvIterItem::Cell(cell) = index.row(2).next().unwrap() else {
+    ///     panic!("Expected a cell");
+    /// };
+    /// assert_eq!(cell.buf, b"4");
+    /// ```
+    pub fn build_index(self) -> CsvIndex<'a, SEP> {
+        let terminator = self.terminator;
+        let quote = self.quote;
+        let escape = self.escape;
+        let comment_prefix = self.comment_prefix;
+        let buf = self.buf;
+
+        let mut row_starts = Vec::new();
+        if let IterState::Cell(start) = self.state {
+            row_starts.push(start);
+        }
+
+        let mut csv = self;
+        while let Some(item) = csv.next() {
+            if let CsvIterItem::LineEnd = item {
+                if let IterState::Cell(start) = csv.state {
+                    row_starts.push(start);
+                }
+            }
+        }
+
+        // A trailing terminator starts a phantom empty row right at EOF; drop it, matching how
+        // `next()` itself never yields anything for it.
+        if row_starts.last() == Some(&buf.len()) {
+            row_starts.pop();
+        }
+
+        CsvIndex {
+            buf,
+            row_starts,
+            terminator,
+            quote,
+            escape,
+            comment_prefix,
+        }
+    }
+
+    /// Sets the record terminator mode, which controls how row boundaries are recognized.
+    ///
+    /// Defaults to [`RecordTerminator::CRLF`], which recognizes `\r`, `\n`, and `\r\n` as a row
+    /// break. Use [`RecordTerminator::Any`] for files using a different single-byte separator,
+    /// such as a bare `\r` (old Mac line endings).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::{Csv, RecordTerminator};
+    ///
+    /// // Parsing a file using a bare `\r` as the row separator
+    /// let csv = Csv::new(b"a,b,c\r1,2,3").with_terminator(RecordTerminator::Any(b'\r'));
+    /// ```
+    pub fn with_terminator(mut self, terminator: RecordTerminator) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Sets the quote character, which defaults to `"` (double quote).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::Csv;
+    ///
+    /// // Parsing a file that quotes with `'` instead of `"`
+    /// let csv = Csv::new(b"'a,b',c\n1,2,3").with_quote(b'\'');
+    /// ```
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets the escape byte used inside quoted cells.
+    ///
+    /// By default, an embedded quote is written as two adjacent quote characters (RFC 4180
+    /// doubling), e.g. `"a""b"` for `a"b`. Calling this switches to backslash-style escaping
+    /// instead, e.g. `"a\"b"` for `a"b` with `with_escape(b'\\')`: a quote preceded by the escape
+    /// byte no longer closes the quoted cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::Csv;
+    ///
+    /// let csv = Csv::new(br#""a\"b",c"#).with_escape(b'\\');
+    /// ```
+    pub fn with_escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Sets the comment prefix. Rows whose first bytes match `prefix` are skipped entirely,
+    /// without being yielded as cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::{Csv, CsvIterItem};
+    ///
+    /// let mut csv = Csv::new(b"# license header\na,b,c\n1,2,3").with_comment_prefix(b"#");
+    /// let CsvIterItem::Cell(cell) = csv.next().unwrap() else {
+    ///     panic!("Expected a cell");
+    /// };
+    /// assert_eq!(cell.buf, b"a");
+    /// ```
+    pub fn with_comment_prefix(mut self, prefix: &'a [u8]) -> Self {
+        self.comment_prefix = Some(prefix);
+        self
     }
 
     /// Skips the first `n` rows.
@@ -155,13 +309,13 @@ impl<'a, const SEP: u8> Csv<'a, SEP> {
     pub fn skip_rows(mut self, n: usize) -> Self {
         let mut start = match self.state {
             IterState::Cell(start) => start,
-            IterState::LineEnd(lf) => lf + 1,
+            IterState::LineEnd(next_start) => next_start,
             IterState::Done => return self,
         };
 
         for _ in 0..n {
-            if let Some(index_relative) = memchr::memchr(b'\n', &self.buf[start..]) {
-                start += index_relative + 1;
+            if let Some((index_relative, len)) = self.terminator.find(&self.buf[start..]) {
+                start += index_relative + len;
             } else {
                 self.state = IterState::Done;
                 break;
@@ -170,6 +324,180 @@ impl<'a, const SEP: u8> Csv<'a, SEP> {
         self.state = IterState::Cell(start);
         self
     }
+
+    /// Sniffs out a leading preamble and skips it, returning a [`Csv`] positioned at the first
+    /// row whose column count (counted by occurrences of the separator, ignoring quoting) is
+    /// repeated by the *two* rows that follow it. Earlier rows with a different shape — license
+    /// headers, metadata blocks, etc. — are treated as preamble and skipped.
+    ///
+    /// Requiring two confirming rows (not just one) avoids mistaking a multi-line preamble for
+    /// the data block itself: several comment lines in a row often coincidentally share a column
+    /// count (e.g. a single-column shape for lines with no separator at all).
+    ///
+    /// If no row's column count is confirmed this way, the last row seen is used as a
+    /// best-effort guess.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::{Csv, CsvIterItem};
+    ///
+    /// let mut csv = Csv::new(b"license info\na,b,c\n1,2,3").auto_skip();
+    /// let CsvIterItem::Cell(cell) = csv.next().unwrap() else {
+    ///     panic!("Expected a cell");
+    /// };
+    /// assert_eq!(cell.buf, b"a");
+    /// ```
+    ///
+    /// A multi-line preamble whose lines happen to share a column count is still skipped in
+    /// full, since the header row is confirmed only once the data rows after it agree too:
+    ///
+    /// ```
+    /// use lazycsv::{Csv, CsvIterItem};
+    ///
+    /// let mut csv =
+    ///     Csv::new(b"# File generated by Foo\n# Version 1.0\nname,value\na,1\nb,2\n").auto_skip();
+    /// let CsvIterItem::Cell(cell) = csv.next().unwrap() else {
+    ///     panic!("Expected a cell");
+    /// };
+    /// assert_eq!(cell.buf, b"name");
+    /// ```
+    pub fn auto_skip(mut self) -> Self {
+        let start = match self.state {
+            IterState::Cell(start) => start,
+            IterState::LineEnd(next_start) => next_start,
+            IterState::Done => return self,
+        };
+
+        let count_columns = |line: &[u8]| line.iter().filter(|&&b| b == SEP).count() + 1;
+
+        let mut cursor = start;
+        // The two most recently seen rows, oldest first, as (start offset, column count).
+        let mut prev: [Option<(usize, usize)>; 2] = [None, None];
+
+        while let Some((pos, len)) = self.terminator.find(&self.buf[cursor..]) {
+            let cols = count_columns(&self.buf[cursor..cursor + pos]);
+            if let [Some((candidate, a)), Some((_, b))] = prev {
+                if a == cols && b == cols {
+                    self.state = IterState::Cell(candidate);
+                    return self;
+                }
+            }
+            prev = [prev[1], Some((cursor, cols))];
+            cursor += pos + len;
+        }
+
+        // A final row without a terminator after it can still confirm the candidate.
+        if cursor < self.buf.len() {
+            let cols = count_columns(&self.buf[cursor..]);
+            if let [Some((candidate, a)), Some((_, b))] = prev {
+                if a == cols && b == cols {
+                    self.state = IterState::Cell(candidate);
+                    return self;
+                }
+            }
+        }
+
+        self.state = IterState::Cell(prev[1].map_or(start, |(pos, _)| pos));
+        self
+    }
+
+    /// Trims the last `n` rows off the end of the buffer, as if the underlying buffer had ended
+    /// earlier. Useful for skipping epilog lines (footnotes, checksums, etc.).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::{Csv, CsvIterItem};
+    ///
+    /// let mut csv = Csv::new(b"a,b,c\n1,2,3\ngenerated by someapp").skip_last_rows(1);
+    /// let mut last_cell = None;
+    /// while let Some(item) = csv.next() {
+    ///     if let CsvIterItem::Cell(cell) = item {
+    ///         last_cell = Some(cell);
+    ///     }
+    /// }
+    /// assert_eq!(last_cell.unwrap().buf, b"3");
+    /// ```
+    pub fn skip_last_rows(mut self, n: usize) -> Self {
+        let mut end = self.buf.len();
+        let mut cut = end;
+
+        // A trailing terminator belongs to the actual last row rather than an extra empty one;
+        // fold it into the row being skipped instead of counting it on its own.
+        if let Some((pos, len)) = self.terminator.rfind(&self.buf[..end]) {
+            if pos + len == end {
+                end = pos;
+            }
+        }
+
+        for _ in 0..n {
+            match self.terminator.rfind(&self.buf[..end]) {
+                Some((pos, len)) => {
+                    cut = pos + len;
+                    end = pos;
+                }
+                None => {
+                    cut = 0;
+                    break;
+                }
+            }
+        }
+
+        self.buf = &self.buf[..cut];
+        self
+    }
+}
+
+/// Controls how record (row) boundaries are recognized while parsing.
+///
+/// See [`Csv::with_terminator()`] for usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordTerminator {
+    /// Recognizes `\r`, `\n`, or `\r\n` as a single row break. This is the default.
+    CRLF,
+    /// Recognizes exactly the given byte as a row break, with no CRLF special-casing.
+    Any(u8),
+}
+
+impl RecordTerminator {
+    /// Finds the next occurrence of this terminator in `haystack`, returning its offset and
+    /// byte length (`2` for a `\r\n` pair, `1` otherwise).
+    fn find(self, haystack: &[u8]) -> Option<(usize, usize)> {
+        match self {
+            RecordTerminator::CRLF => {
+                let index = memchr::memchr2(b'\r', b'\n', haystack)?;
+                let len = self.len_at(haystack[index], haystack.get(index + 1).copied());
+                Some((index, len))
+            }
+            RecordTerminator::Any(byte) => memchr::memchr(byte, haystack).map(|index| (index, 1)),
+        }
+    }
+
+    /// Returns the byte length of the terminator starting with `byte`, given the byte that
+    /// follows it, if any.
+    fn len_at(self, byte: u8, next: Option<u8>) -> usize {
+        match self {
+            RecordTerminator::CRLF if byte == b'\r' && next == Some(b'\n') => 2,
+            _ => 1,
+        }
+    }
+
+    /// Finds the last occurrence of this terminator in `haystack`, returning its offset and
+    /// byte length (`2` for a `\r\n` pair, `1` otherwise).
+    fn rfind(self, haystack: &[u8]) -> Option<(usize, usize)> {
+        match self {
+            RecordTerminator::CRLF => {
+                let index = memchr::memrchr2(b'\r', b'\n', haystack)?;
+                if haystack[index] == b'\n' && index != 0 && haystack[index - 1] == b'\r' {
+                    Some((index - 1, 2))
+                } else {
+                    Some((index, 1))
+                }
+            }
+            RecordTerminator::Any(byte) => memchr::memrchr(byte, haystack).map(|index| (index, 1)),
+        }
+    }
 }
 
 enum IterState {
@@ -196,7 +524,21 @@ impl<'a, const SEP: u8> Iterator for Csv<'a, SEP> {
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.state {
-            IterState::Cell(start) => {
+            IterState::Cell(mut start) => {
+                // A cell right after a separator is mid-row; only row starts can be comments.
+                let at_row_start = start == 0 || self.buf[start - 1] != SEP;
+                if let Some(prefix) = self.comment_prefix.filter(|_| at_row_start) {
+                    while self.buf[start..].starts_with(prefix) {
+                        match self.terminator.find(&self.buf[start..]) {
+                            Some((pos, len)) => start += pos + len,
+                            None => {
+                                self.state = IterState::Done;
+                                return None;
+                            }
+                        }
+                    }
+                }
+
                 let mut cursor = start;
                 let mut padding = 0;
                 let mut state = State::Initial;
@@ -204,49 +546,86 @@ impl<'a, const SEP: u8> Iterator for Csv<'a, SEP> {
                 loop {
                     match state {
                         State::Initial => {
-                            let Some(index_relative) =
-                                memchr::memchr3(SEP, b'\n', b'"', &self.buf[cursor..])
-                            else {
+                            let Some(index_relative) = (match self.terminator {
+                                RecordTerminator::CRLF => {
+                                    let sep_or_quote =
+                                        memchr::memchr2(SEP, self.quote, &self.buf[cursor..]);
+                                    let term = memchr::memchr2(b'\r', b'\n', &self.buf[cursor..]);
+                                    match (sep_or_quote, term) {
+                                        (Some(a), Some(b)) => Some(a.min(b)),
+                                        (Some(a), None) => Some(a),
+                                        (None, Some(b)) => Some(b),
+                                        (None, None) => None,
+                                    }
+                                }
+                                RecordTerminator::Any(terminator) => {
+                                    memchr::memchr3(SEP, self.quote, terminator, &self.buf[cursor..])
+                                }
+                            }) else {
                                 self.state = IterState::Done;
                                 break None;
                             };
                             let index = index_relative + cursor;
                             // SAFETY: since `memchr` guarantees that `index_relative` is within the bounds of `self.buf[cursor..]`, it's also guaranteed that `index_relative + cursor` is within the bounds of `self.buf`.
                             let c = unsafe { *self.buf.get_unchecked(index) };
-                            if c == b'"' {
+                            if c == self.quote {
                                 state = State::Quoted;
                                 cursor = index + 1;
                                 padding = 1;
-                            } else {
-                                // SAFETY: `index - 1` is checked to be within the bounds of `self.buf`.
-                                let is_crlf = c == b'\n'
-                                    && index != 0
-                                    && unsafe { *self.buf.get_unchecked(index - 1) } == b'\r';
-                                let padding_end = padding + (is_crlf as usize);
+                            } else if c == SEP {
                                 let cell = Cell {
-                                    buf: &self.buf[(start + padding)..(index - padding_end)],
+                                    buf: &self.buf[(start + padding)..(index - padding)],
+                                    quoted: padding == 1,
+                                    quote: self.quote,
+                                    escape: self.escape,
+                                    start: start + padding,
                                 };
-                                self.state = match c == b'\n' {
-                                    true => IterState::LineEnd(index),
-                                    false => IterState::Cell(index + 1),
+                                self.state = IterState::Cell(index + 1);
+                                break Some(CsvIterItem::Cell(cell));
+                            } else {
+                                let len = self.terminator.len_at(c, self.buf.get(index + 1).copied());
+                                let cell = Cell {
+                                    buf: &self.buf[(start + padding)..(index - padding)],
+                                    quoted: padding == 1,
+                                    quote: self.quote,
+                                    escape: self.escape,
+                                    start: start + padding,
                                 };
+                                self.state = IterState::LineEnd(index + len);
                                 break Some(CsvIterItem::Cell(cell));
                             }
                         }
                         State::Quoted => {
-                            let Some(index_relative) = memchr::memchr(b'"', &self.buf[cursor..])
+                            let Some(index_relative) =
+                                memchr::memchr(self.quote, &self.buf[cursor..])
                             else {
                                 self.state = IterState::Done;
                                 break None;
                             };
-                            state = State::Initial;
-                            cursor = cursor + index_relative + 1;
+                            let index = cursor + index_relative;
+                            // An escape byte only takes effect if it isn't itself escaped, so count
+                            // the run of consecutive escape bytes immediately preceding the quote
+                            // and check its parity rather than looking back a single byte.
+                            let is_escaped = self.escape.is_some_and(|escape| {
+                                let mut run = 0;
+                                // SAFETY: `run < index` is checked on every iteration, so `index - 1 - run` stays within the bounds of `self.buf`.
+                                while run < index
+                                    && unsafe { *self.buf.get_unchecked(index - 1 - run) } == escape
+                                {
+                                    run += 1;
+                                }
+                                run % 2 == 1
+                            });
+                            cursor = index + 1;
+                            if !is_escaped {
+                                state = State::Initial;
+                            }
                         }
                     }
                 }
             }
-            IterState::LineEnd(pos) => {
-                self.state = IterState::Cell(pos + 1);
+            IterState::LineEnd(next_start) => {
+                self.state = IterState::Cell(next_start);
                 Some(CsvIterItem::LineEnd)
             }
             IterState::Done => None,
@@ -264,6 +643,7 @@ impl<'a, const SEP: u8> Iterator for Csv<'a, SEP> {
 /// - `SEP`: The separator character in `u8`, defaults to `b','`.
 pub struct CsvRowIter<'a, const COLS: usize, const SEP: u8> {
     csv: Csv<'a, SEP>,
+    record: usize,
 }
 
 impl<const COLS: usize, const SEP: u8> CsvRowIter<'_, COLS, SEP> {
@@ -289,6 +669,7 @@ impl<const COLS: usize, const SEP: u8> CsvRowIter<'_, COLS, SEP> {
     pub fn skip(self, n: usize) -> Self {
         Self {
             csv: self.csv.skip_rows(n),
+            record: self.record + n,
         }
     }
 }
@@ -298,28 +679,41 @@ impl<'a, const COLS: usize, const SEP: u8> Iterator for CsvRowIter<'a, COLS, SEP
 
     fn next(&mut self) -> Option<Self::Item> {
         let mut arr = [const { MaybeUninit::uninit() }; COLS];
+        let mut row_start = None;
         for i in 0..COLS {
             match self.csv.next() {
                 Some(CsvIterItem::Cell(cell)) => {
+                    if row_start.is_none() {
+                        row_start = Some(cell.start);
+                    }
                     // SAFETY: we have to initialize the cell beforehand
                     unsafe { arr.get_unchecked_mut(i).write(cell) };
                 }
                 Some(CsvIterItem::LineEnd) => {
+                    let record = self.record;
+                    self.record += 1;
                     return Some(Err(RowIterError::ColumnCountSmallerThanExpected {
                         expected: COLS,
-                        actual: i - 1,
-                    }))
+                        actual: i,
+                        record,
+                        offset: row_start.unwrap_or(0),
+                    }));
                 }
                 None => return None,
             }
         }
 
         if !matches!(self.csv.next(), Some(CsvIterItem::LineEnd)) {
+            let record = self.record;
+            self.record += 1;
             return Some(Err(RowIterError::ColumnCountLargerThanExpected {
                 expected: COLS,
+                record,
+                offset: row_start.unwrap_or(0),
             }));
         }
 
+        self.record += 1;
         Some(Ok(arr.map(|mem| unsafe { mem.assume_init() })))
     }
 }
@@ -328,42 +722,293 @@ impl<'a, const COLS: usize, const SEP: u8> Iterator for CsvRowIter<'a, COLS, SEP
 #[derive(Error, Debug)]
 pub enum RowIterError {
     /// Found smaller number of columns than expected.
-    #[error("expected {expected} columns, but new row started after parsing {actual} columns")]
+    #[error(
+        "expected {expected} columns, but new row started after parsing {actual} columns \
+         (record {record}, byte offset {offset})"
+    )]
     ColumnCountSmallerThanExpected {
         /// The expected number of columns.
         expected: usize,
         /// The actual number of columns.
         actual: usize,
+        /// The 0-based index of the record (row) the error occurred in, counting from wherever
+        /// this [`CsvRowIter`] started.
+        record: usize,
+        /// The byte offset into the original buffer of the record's first cell.
+        offset: usize,
     },
 
     /// Found larger number of columns than expected.
-    #[error("expected {expected} columns, but no newline found after parsing {expected} columns")]
+    #[error(
+        "expected {expected} columns, but no newline found after parsing {expected} columns \
+         (record {record}, byte offset {offset})"
+    )]
     ColumnCountLargerThanExpected {
         /// The expected number of columns.
         expected: usize,
+        /// The 0-based index of the record (row) the error occurred in, counting from wherever
+        /// this [`CsvRowIter`] started.
+        record: usize,
+        /// The byte offset into the original buffer of the record's first cell.
+        offset: usize,
     },
 }
 
+/// An iterator that yields each row as a slice of cells, without a fixed column count.
+///
+/// Can be created by calling [`Csv::into_flexible_rows()`]. Since rows may have differing
+/// lengths, this doesn't implement [`Iterator`] — call [`CsvFlexibleRowIter::next_row()`] with a
+/// reusable backing buffer instead, so parsing doesn't allocate per row.
+///
+/// ### `const` Parameters
+///
+/// - `SEP`: The separator character in `u8`, defaults to `b','`.
+pub struct CsvFlexibleRowIter<'a, const SEP: u8> {
+    csv: Csv<'a, SEP>,
+}
+
+impl<'a, const SEP: u8> CsvFlexibleRowIter<'a, SEP> {
+    /// Skips the first `n` rows.
+    ///
+    /// Using this function is more efficient than discarding rows with
+    /// [`CsvFlexibleRowIter::next_row()`], as it only looks for record terminators instead of
+    /// trying to recognize cells.
+    pub fn skip(self, n: usize) -> Self {
+        Self {
+            csv: self.csv.skip_rows(n),
+        }
+    }
+
+    /// Parses the next row into `buf`, clearing it first, and returns it as a slice.
+    ///
+    /// Returns `None` once there are no more rows. `buf` is cleared and refilled on every call,
+    /// so the same buffer can be reused across rows without allocating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::Csv;
+    ///
+    /// let mut rows = Csv::new(b"a,b,c\n1,2\n3,4,5,6\n").into_flexible_rows();
+    /// let mut buf = Vec::new();
+    ///
+    /// assert_eq!(rows.next_row(&mut buf).unwrap().len(), 3);
+    /// assert_eq!(rows.next_row(&mut buf).unwrap().len(), 2);
+    /// assert_eq!(rows.next_row(&mut buf).unwrap().len(), 4);
+    /// assert!(rows.next_row(&mut buf).is_none());
+    /// ```
+    pub fn next_row<'b>(&mut self, buf: &'b mut Vec<Cell<'a>>) -> Option<&'b [Cell<'a>]> {
+        buf.clear();
+        while let CsvIterItem::Cell(cell) = self.csv.next()? {
+            buf.push(cell);
+        }
+        Some(buf.as_slice())
+    }
+}
+
+/// A precomputed index of row-start byte offsets into a [`Csv`]'s buffer, allowing random access
+/// to any row without re-parsing from the beginning.
+///
+/// Can be created by calling [`Csv::build_index()`].
+///
+/// ### `const` Parameters
+///
+/// - `SEP`: The separator character in `u8`, defaults to `b','`.
+pub struct CsvIndex<'a, const SEP: u8> {
+    buf: &'a [u8],
+    row_starts: Vec<usize>,
+    terminator: RecordTerminator,
+    quote: u8,
+    escape: Option<u8>,
+    comment_prefix: Option<&'a [u8]>,
+}
+
+impl<'a, const SEP: u8> CsvIndex<'a, SEP> {
+    /// Returns the number of indexed rows.
+    pub fn len(&self) -> usize {
+        self.row_starts.len()
+    }
+
+    /// Returns `true` if the index contains no rows.
+    pub fn is_empty(&self) -> bool {
+        self.row_starts.is_empty()
+    }
+
+    /// Returns a [`Csv`] parser positioned at the first byte of row `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is out of bounds, i.e. not less than [`CsvIndex::len()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::{Csv, CsvIterItem};
+    ///
+    /// let index = Csv::new(b"a,b,c\n1,2,3\n4,5,6\n").build_index();
+    /// let CsvIterItem::Cell(cell) = index.row(0).next().unwrap() else {
+    ///     panic!("Expected a cell");
+    /// };
+    /// assert_eq!(cell.buf, b"a");
+    /// ```
+    pub fn row(&self, n: usize) -> Csv<'a, SEP> {
+        Csv {
+            buf: self.buf,
+            state: IterState::Cell(self.row_starts[n]),
+            terminator: self.terminator,
+            quote: self.quote,
+            escape: self.escape,
+            comment_prefix: self.comment_prefix,
+        }
+    }
+}
+
 /// A cell in a CSV row.
 #[derive(Debug, Clone, Eq)]
 pub struct Cell<'a> {
     /// The underlying buffer, containing potentially quoted cell content as bytes.
     pub buf: &'a [u8],
+    quoted: bool,
+    quote: u8,
+    escape: Option<u8>,
+    start: usize,
 }
 
 impl<'a> Cell<'a> {
     /// Converts the cell to a string.
     ///
-    /// Calling this function performs a UTF-8 validation and dequotes the cell if necessary.
+    /// Calling this function performs a UTF-8 validation and dequotes the cell if necessary,
+    /// using whichever quoting mode was active on the [`Csv`] parser that produced it: doubled
+    /// quote characters by default, or an escape byte if [`Csv::with_escape()`] was used.
+    ///
+    /// A run of escape bytes is paired up left-to-right, same as the scanner's own rule for
+    /// deciding whether a quote is escaped: each adjacent pair of escape bytes collapses to one
+    /// literal escape byte, and only a leftover, unpaired escape byte escapes the byte after it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::{Csv, CsvIterItem};
+    ///
+    /// // Three escape bytes before the embedded quote: the first two pair up into one literal
+    /// // `\`, and the third escapes the quote.
+    /// let mut csv = Csv::new(br#""a\\\"b",c"#).with_escape(b'\\');
+    /// let CsvIterItem::Cell(cell) = csv.next().unwrap() else {
+    ///     panic!("Expected a cell");
+    /// };
+    /// assert_eq!(cell.try_as_str().unwrap(), "a\\\"b");
+    /// ```
     pub fn try_as_str(&self) -> Result<Cow<'a, str>, std::str::Utf8Error> {
-        std::str::from_utf8(self.buf).map(|s| {
-            // SAFETY: since `s.as_bytes()` is guaranteed to be valid UTF-8, it's also guaranteed that the first character is '"' if the first byte is b'"' due to UTF-8 representing ASCII characters as-is.
-            if !s.is_empty() && unsafe { *s.as_bytes().get_unchecked(0) } == b'"' {
-                Cow::Owned(s.replace("\"\"", "\""))
+        std::str::from_utf8(self.buf).map(|s| self.dequote(s))
+    }
+
+    /// Converts the cell to a string, same as [`Cell::try_as_str()`], but replaces any invalid
+    /// UTF-8 byte sequences with the replacement character (`U+FFFD`) instead of failing.
+    ///
+    /// Returns `Cow::Borrowed` only when the cell is both valid UTF-8 and doesn't need dequoting;
+    /// otherwise this allocates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lazycsv::{Csv, CsvIterItem};
+    ///
+    /// let mut csv = Csv::new(b"a,\xff\xffb,c\n1,2,3");
+    /// let CsvIterItem::Cell(_) = csv.next().unwrap() else {
+    ///     panic!("Expected a cell");
+    /// };
+    /// let CsvIterItem::Cell(cell) = csv.next().unwrap() else {
+    ///     panic!("Expected a cell");
+    /// };
+    /// assert_eq!(cell.as_str_lossy(), "\u{fffd}\u{fffd}b");
+    /// ```
+    pub fn as_str_lossy(&self) -> Cow<'a, str> {
+        match std::str::from_utf8(self.buf) {
+            Ok(s) => self.dequote(s),
+            Err(_) => Cow::Owned(self.dequote_lossy(String::from_utf8_lossy(self.buf).into_owned())),
+        }
+    }
+
+    fn dequote(&self, s: &'a str) -> Cow<'a, str> {
+        match self.dequote_bytes() {
+            Cow::Borrowed(_) => Cow::Borrowed(s),
+            // SAFETY: `dequote_bytes()` only ever copies whole bytes of `s` (which is valid
+            // UTF-8) verbatim or drops a single-byte ASCII escape/quote, so it can't split a
+            // multi-byte UTF-8 sequence.
+            Cow::Owned(buf) => Cow::Owned(unsafe { String::from_utf8_unchecked(buf) }),
+        }
+    }
+
+    /// Same as [`Cell::dequote()`], but operating directly on the raw cell bytes without
+    /// assuming they're valid UTF-8. Used by [`Cell::dequote()`] itself and by the `serde`
+    /// `deserialize_bytes`/`deserialize_byte_buf` impls.
+    fn dequote_bytes(&self) -> Cow<'a, [u8]> {
+        if !self.quoted {
+            return Cow::Borrowed(self.buf);
+        }
+        match self.escape {
+            Some(escape) if memchr::memchr(escape, self.buf).is_some() => {
+                Cow::Owned(Self::unescape(self.buf, self.quote, escape))
+            }
+            None if memchr::memchr(self.quote, self.buf).is_some() => {
+                Cow::Owned(Self::undouble(self.buf, self.quote))
+            }
+            _ => Cow::Borrowed(self.buf),
+        }
+    }
+
+    /// Collapses `escape`-prefixed quote and escape bytes (e.g. `\"` -> `"`, `\\` -> `\`) into
+    /// their unescaped form, pairing a run of escape bytes left-to-right the same way the
+    /// scanner's escape-parity check does: each adjacent pair of escape bytes collapses to one
+    /// literal escape byte, and only a final, unpaired escape byte can escape the byte after it.
+    fn unescape(buf: &[u8], quote: u8, escape: u8) -> Vec<u8> {
+        let mut out = Vec::with_capacity(buf.len());
+        let mut i = 0;
+        while i < buf.len() {
+            if buf[i] == escape && matches!(buf.get(i + 1), Some(&b) if b == escape || b == quote) {
+                out.push(buf[i + 1]);
+                i += 2;
             } else {
-                Cow::Borrowed(s)
+                out.push(buf[i]);
+                i += 1;
             }
-        })
+        }
+        out
+    }
+
+    /// Collapses doubled quote bytes (e.g. `""` -> `"`) into a single quote.
+    fn undouble(buf: &[u8], quote: u8) -> Vec<u8> {
+        let mut out = Vec::with_capacity(buf.len());
+        let mut i = 0;
+        while i < buf.len() {
+            out.push(buf[i]);
+            if buf[i] == quote && buf.get(i + 1) == Some(&quote) {
+                i += 2;
+            } else {
+                i += 1;
+            }
+        }
+        out
+    }
+
+    /// Same as [`Cell::dequote()`], but operating on an already-owned, already-valid-UTF-8
+    /// string (the output of a lossy UTF-8 conversion) instead of borrowing from `self.buf`.
+    fn dequote_lossy(&self, s: String) -> String {
+        if !self.quoted {
+            return s;
+        }
+        let buf = s.as_bytes();
+        let out = match self.escape {
+            Some(escape) if memchr::memchr(escape, buf).is_some() => {
+                Self::unescape(buf, self.quote, escape)
+            }
+            None if memchr::memchr(self.quote, buf).is_some() => Self::undouble(buf, self.quote),
+            _ => return s,
+        };
+        // SAFETY: `s` is valid UTF-8, and `unescape()`/`undouble()` only ever copy whole bytes of
+        // it verbatim or drop a single-byte ASCII escape/quote, so the result can't split a
+        // multi-byte UTF-8 sequence.
+        unsafe { String::from_utf8_unchecked(out) }
     }
 }
 
@@ -390,3 +1035,384 @@ impl Ord for Cell<'_> {
         self.buf.cmp(other.buf)
     }
 }
+
+/// Errors returned while deserializing a row into a typed value via [`CsvRowIter::deserialize()`]
+/// or [`CsvRowIter::deserialize_with_headers()`].
+#[cfg(feature = "serde")]
+#[derive(Error, Debug)]
+pub enum RowDeserializeError {
+    /// Parsing the underlying row itself failed.
+    #[error(transparent)]
+    Row(#[from] RowIterError),
+
+    /// A specific field failed to deserialize.
+    #[error("field {field}: {message}")]
+    Field {
+        /// The index of the field that failed to deserialize. `usize::MAX` if a more specific
+        /// index wasn't available when the error was raised.
+        field: usize,
+        /// A message describing why deserialization failed.
+        message: String,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl serde::de::Error for RowDeserializeError {
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        RowDeserializeError::Field {
+            field: usize::MAX,
+            message: alloc::format!("{msg}"),
+        }
+    }
+}
+
+/// A single cell, deserialized via its [`Cell::try_as_str()`] dequoting rules.
+///
+/// `'a` is tied to both the deserializer's lifetime and the cell's buffer lifetime, so borrowed
+/// `&str`/`&[u8]` struct fields can be produced without allocating.
+#[cfg(feature = "serde")]
+struct CellDeserializer<'a> {
+    cell: Cell<'a>,
+    field: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<'a> CellDeserializer<'a> {
+    fn as_str(&self) -> Result<Cow<'a, str>, RowDeserializeError> {
+        self.cell.try_as_str().map_err(|_| RowDeserializeError::Field {
+            field: self.field,
+            message: String::from("invalid UTF-8"),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+macro_rules! deserialize_number {
+    ($method:ident, $visit:ident, $ty:ty) => {
+        fn $method<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            let s = self.as_str()?;
+            let value: $ty = s.trim().parse().map_err(|_| RowDeserializeError::Field {
+                field: self.field,
+                message: alloc::format!("invalid {} value: {s:?}", stringify!($ty)),
+            })?;
+            visitor.$visit(value)
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserializer<'de> for CellDeserializer<'de> {
+    type Error = RowDeserializeError;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    deserialize_number!(deserialize_bool, visit_bool, bool);
+    deserialize_number!(deserialize_i8, visit_i8, i8);
+    deserialize_number!(deserialize_i16, visit_i16, i16);
+    deserialize_number!(deserialize_i32, visit_i32, i32);
+    deserialize_number!(deserialize_i64, visit_i64, i64);
+    deserialize_number!(deserialize_i128, visit_i128, i128);
+    deserialize_number!(deserialize_u8, visit_u8, u8);
+    deserialize_number!(deserialize_u16, visit_u16, u16);
+    deserialize_number!(deserialize_u32, visit_u32, u32);
+    deserialize_number!(deserialize_u64, visit_u64, u64);
+    deserialize_number!(deserialize_u128, visit_u128, u128);
+    deserialize_number!(deserialize_f32, visit_f32, f32);
+    deserialize_number!(deserialize_f64, visit_f64, f64);
+
+    fn deserialize_char<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let s = self.as_str()?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(RowDeserializeError::Field {
+                field: self.field,
+                message: alloc::format!("expected a single character, got {s:?}"),
+            }),
+        }
+    }
+
+    fn deserialize_str<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.as_str()? {
+            Cow::Borrowed(s) => visitor.visit_borrowed_str(s),
+            Cow::Owned(s) => visitor.visit_string(s),
+        }
+    }
+
+    fn deserialize_string<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.cell.dequote_bytes() {
+            Cow::Borrowed(b) => visitor.visit_borrowed_bytes(b),
+            Cow::Owned(b) => visitor.visit_byte_buf(b),
+        }
+    }
+
+    fn deserialize_byte_buf<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.cell.buf.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+/// [`serde::de::SeqAccess`] over a row's cells, matching fields by position.
+#[cfg(feature = "serde")]
+struct RowSeqAccess<'a, 'c> {
+    cells: &'c [Cell<'a>],
+    index: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::de::SeqAccess<'a> for RowSeqAccess<'a, '_> {
+    type Error = RowDeserializeError;
+
+    fn next_element_seed<T: serde::de::DeserializeSeed<'a>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        let Some(cell) = self.cells.get(self.index) else {
+            return Ok(None);
+        };
+        let value = seed.deserialize(CellDeserializer {
+            cell: cell.clone(),
+            field: self.index,
+        })?;
+        self.index += 1;
+        Ok(Some(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.cells.len() - self.index)
+    }
+}
+
+/// [`serde::de::MapAccess`] over a row's cells, matching fields by the header row's column names.
+#[cfg(feature = "serde")]
+struct RowMapAccess<'a, 'c> {
+    cells: &'c [Cell<'a>],
+    headers: &'c [Cow<'a, str>],
+    fields: &'static [&'static str],
+    index: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::de::MapAccess<'a> for RowMapAccess<'a, '_> {
+    type Error = RowDeserializeError;
+
+    fn next_key_seed<K: serde::de::DeserializeSeed<'a>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        while let Some(name) = self.headers.get(self.index) {
+            if self.fields.contains(&name.as_ref()) {
+                use serde::de::IntoDeserializer;
+                return seed.deserialize(name.as_ref().into_deserializer()).map(Some);
+            }
+            self.index += 1;
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<V: serde::de::DeserializeSeed<'a>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let cell = self.cells.get(self.index).ok_or_else(|| RowDeserializeError::Field {
+            field: self.index,
+            message: String::from("missing value for column"),
+        })?;
+        let value = seed.deserialize(CellDeserializer {
+            cell: cell.clone(),
+            field: self.index,
+        })?;
+        self.index += 1;
+        Ok(value)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.cells.len().saturating_sub(self.index))
+    }
+}
+
+/// Deserializes a whole row, either positionally or (when `headers` is set) by column name.
+#[cfg(feature = "serde")]
+struct RowDeserializer<'a, 'c> {
+    cells: &'c [Cell<'a>],
+    headers: Option<&'c [Cow<'a, str>]>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserializer<'de> for RowDeserializer<'de, '_> {
+    type Error = RowDeserializeError;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_seq<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(RowSeqAccess {
+            cells: self.cells,
+            index: 0,
+        })
+    }
+
+    fn deserialize_tuple<V: serde::de::Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V: serde::de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.headers {
+            Some(headers) => visitor.visit_map(RowMapAccess {
+                cells: self.cells,
+                headers,
+                fields,
+                index: 0,
+            }),
+            None => self.deserialize_seq(visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// Iterator adapter that deserializes each row into `T` via `serde`.
+///
+/// Created by [`CsvRowIter::deserialize()`] or [`CsvRowIter::deserialize_with_headers()`].
+#[cfg(feature = "serde")]
+pub struct DeserializeIter<'a, const COLS: usize, const SEP: u8, T> {
+    rows: CsvRowIter<'a, COLS, SEP>,
+    headers: Option<[Cow<'a, str>; COLS]>,
+    marker: core::marker::PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "serde")]
+impl<'a, const COLS: usize, const SEP: u8, T: serde::Deserialize<'a>> Iterator
+    for DeserializeIter<'a, COLS, SEP, T>
+{
+    type Item = Result<T, RowDeserializeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = match self.rows.next()? {
+            Ok(row) => row,
+            Err(err) => return Some(Err(RowDeserializeError::Row(err))),
+        };
+        let headers = self.headers.as_ref().map(|headers| headers.as_slice());
+        Some(T::deserialize(RowDeserializer {
+            cells: &row,
+            headers,
+        }))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, const COLS: usize, const SEP: u8> CsvRowIter<'a, COLS, SEP> {
+    /// Maps each row to `T` via `serde`, matching struct fields by position.
+    ///
+    /// Requires the [`serde`](https://docs.rs/serde) crate; enable this with the `serde` feature.
+    /// Borrowed `&str`/`&[u8]` fields on `T` are produced without allocating; dequoting still
+    /// allocates when a quoted cell actually contains an escaped or doubled quote character, same
+    /// as [`Cell::try_as_str()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde")] {
+    /// use lazycsv::Csv;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Record<'a> {
+    ///     name: &'a str,
+    ///     age: u8,
+    /// }
+    ///
+    /// let mut rows = Csv::new(b"alice,30\nbob,25\n").into_rows::<2>().deserialize::<Record>();
+    /// let alice = rows.next().unwrap()?;
+    /// assert_eq!((alice.name, alice.age), ("alice", 30));
+    /// # }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn deserialize<T: serde::Deserialize<'a>>(self) -> DeserializeIter<'a, COLS, SEP, T> {
+        DeserializeIter {
+            rows: self,
+            headers: None,
+            marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Like [`CsvRowIter::deserialize()`], but consumes the first row as a header and matches
+    /// struct fields to columns by name instead of by position.
+    ///
+    /// Requires the [`serde`](https://docs.rs/serde) crate; enable this with the `serde` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde")] {
+    /// use lazycsv::Csv;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Record<'a> {
+    ///     age: u8,
+    ///     name: &'a str,
+    /// }
+    ///
+    /// let mut rows = Csv::new(b"name,age\nalice,30\n")
+    ///     .into_rows::<2>()
+    ///     .deserialize_with_headers::<Record>()?;
+    /// let alice = rows.next().unwrap()?;
+    /// assert_eq!((alice.name, alice.age), ("alice", 30));
+    /// # }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn deserialize_with_headers<T: serde::Deserialize<'a>>(
+        mut self,
+    ) -> Result<DeserializeIter<'a, COLS, SEP, T>, RowDeserializeError> {
+        let header_row = self.next().ok_or_else(|| RowDeserializeError::Field {
+            field: usize::MAX,
+            message: String::from("missing header row"),
+        })??;
+
+        let mut headers: [Cow<'a, str>; COLS] = core::array::from_fn(|_| Cow::Borrowed(""));
+        for (field, (slot, cell)) in headers.iter_mut().zip(header_row.iter()).enumerate() {
+            *slot = cell.try_as_str().map_err(|_| RowDeserializeError::Field {
+                field,
+                message: String::from("invalid UTF-8 in header row"),
+            })?;
+        }
+
+        Ok(DeserializeIter {
+            rows: self,
+            headers: Some(headers),
+            marker: core::marker::PhantomData,
+        })
+    }
+}